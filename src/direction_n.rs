@@ -0,0 +1,142 @@
+use crate::direction::Direction;
+use crate::vecn::VecN;
+
+/// One of the `2 * D` axis-aligned unit directions in `D`-dimensional space:
+/// a step of `sign` (`1` or `-1`) along `axis`. This is the N-dimensional
+/// generalization of [`crate::Direction`], which only enumerates the four
+/// axis-aligned directions of the plane.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct DirectionN<const D: usize> {
+    axis: usize,
+    sign: i32,
+}
+
+impl<const D: usize> DirectionN<D> {
+    /// The unit direction pointing in the positive direction of `axis`.
+    pub fn positive(axis: usize) -> Self {
+        assert!(axis < D, "axis {} out of bounds for {}-dimensional space", axis, D);
+        Self { axis, sign: 1 }
+    }
+
+    /// The unit direction pointing in the negative direction of `axis`.
+    pub fn negative(axis: usize) -> Self {
+        assert!(axis < D, "axis {} out of bounds for {}-dimensional space", axis, D);
+        Self { axis, sign: -1 }
+    }
+
+    /// All `2 * D` axis-aligned unit directions.
+    pub fn all() -> Vec<Self> {
+        (0..D).flat_map(|axis| [Self::positive(axis), Self::negative(axis)]).collect()
+    }
+
+    /// The unit step vector for this direction.
+    pub fn to_vec(self) -> VecN<D> {
+        let mut v = [0; D];
+        v[self.axis] = self.sign;
+        VecN(v)
+    }
+
+    /// Rotates this direction 90° within the plane spanned by `axis_a` and
+    /// `axis_b`, turning `axis_b` towards `axis_a`; directions along any
+    /// other axis are left unchanged. For `D == 2` with `axis_a = 0`
+    /// (East/West) and `axis_b = 1` (North/South), this is a 2D clockwise
+    /// turn (North -> East -> South -> West).
+    pub fn rotate_right(self, axis_a: usize, axis_b: usize) -> Self {
+        if self.axis == axis_a {
+            Self { axis: axis_b, sign: -self.sign }
+        } else if self.axis == axis_b {
+            Self { axis: axis_a, sign: self.sign }
+        } else {
+            self
+        }
+    }
+
+    /// The inverse of [`Self::rotate_right`], turning `axis_a` towards
+    /// `axis_b`. For `D == 2` with `axis_a = 0` (East/West) and `axis_b = 1`
+    /// (North/South), this is a 2D counter-clockwise turn (North -> West ->
+    /// South -> East).
+    pub fn rotate_left(self, axis_a: usize, axis_b: usize) -> Self {
+        if self.axis == axis_a {
+            Self { axis: axis_b, sign: self.sign }
+        } else if self.axis == axis_b {
+            Self { axis: axis_a, sign: -self.sign }
+        } else {
+            self
+        }
+    }
+}
+
+impl DirectionN<2> {
+    /// Maps a [`Direction`] onto its `axis`/`sign` representation, with axis
+    /// 0 as East/West and axis 1 as North/South.
+    pub(crate) fn from_direction(dir: Direction) -> Self {
+        match dir {
+            Direction::East => Self::positive(0),
+            Direction::West => Self::negative(0),
+            Direction::North => Self::positive(1),
+            Direction::South => Self::negative(1),
+        }
+    }
+
+    /// The inverse of [`Self::from_direction`].
+    pub(crate) fn to_direction(self) -> Direction {
+        match (self.axis, self.sign) {
+            (0, 1) => Direction::East,
+            (0, -1) => Direction::West,
+            (1, 1) => Direction::North,
+            (1, -1) => Direction::South,
+            _ => unreachable!("DirectionN<2> must have axis 0 or 1 with sign ±1"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_enumerates_2d_cardinal_directions() {
+        let dirs: std::collections::HashSet<_> = DirectionN::<2>::all().into_iter().map(DirectionN::to_vec).collect();
+        assert_eq!(dirs, [VecN([1, 0]), VecN([-1, 0]), VecN([0, 1]), VecN([0, -1])].into_iter().collect());
+    }
+
+    #[test]
+    fn test_rotate_right_matches_2d_turn_right() {
+        let north = DirectionN::<2>::positive(1);
+        let east = DirectionN::<2>::positive(0);
+        let south = DirectionN::<2>::negative(1);
+        let west = DirectionN::<2>::negative(0);
+
+        assert_eq!(north.rotate_right(0, 1), east);
+        assert_eq!(east.rotate_right(0, 1), south);
+        assert_eq!(south.rotate_right(0, 1), west);
+        assert_eq!(west.rotate_right(0, 1), north);
+    }
+
+    #[test]
+    fn test_rotate_left_matches_2d_turn_left() {
+        let north = DirectionN::<2>::positive(1);
+        let east = DirectionN::<2>::positive(0);
+        let south = DirectionN::<2>::negative(1);
+        let west = DirectionN::<2>::negative(0);
+
+        assert_eq!(north.rotate_left(0, 1), west);
+        assert_eq!(west.rotate_left(0, 1), south);
+        assert_eq!(south.rotate_left(0, 1), east);
+        assert_eq!(east.rotate_left(0, 1), north);
+    }
+
+    #[test]
+    fn test_rotate_ignores_directions_outside_the_plane() {
+        let up = DirectionN::<3>::positive(2);
+        assert_eq!(up.rotate_right(0, 1), up);
+        assert_eq!(up.rotate_left(0, 1), up);
+    }
+
+    #[test]
+    fn test_direction_conversion_roundtrips() {
+        for dir in [Direction::North, Direction::East, Direction::South, Direction::West] {
+            assert_eq!(DirectionN::<2>::from_direction(dir).to_direction(), dir);
+        }
+    }
+}
@@ -1,12 +1,23 @@
-use std::collections::{HashSet, VecDeque};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
 use crate::direction::Direction;
+use crate::repeat_sequence::tokenize;
 use crate::vec2d::Vec2D;
 use std::fmt::{Display, Formatter};
-use std::ops::{Add, Sub};
 use cons_list::ConsList;
 
 mod direction;
+mod direction_n;
+mod repeat_sequence;
+mod rover_n;
 mod vec2d;
+mod vecn;
+mod waypoint_rover;
+
+pub use direction_n::DirectionN;
+pub use rover_n::RoverN;
+pub use vecn::VecN;
+pub use waypoint_rover::WaypointRover;
 
 /// A mars rover described by a position in a 2D grid and a direction (North,East,South,West)
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -19,58 +30,43 @@ impl Rover {
     /// Creates a new rover at the given x and y coordinates facing the given direction
     pub fn new(x: i32, y: i32, direction: Direction) -> Self {
         let res = Self {
-            position: Vec2D(x, y),
+            position: VecN([x, y]),
             direction,
         };
         println!("Landed at {}", res);
         res
     }
 
-    /// Turns the given direction into a delta-vector for modifying the rover's position
-    fn dir_to_vec(dir: Direction) -> Vec2D {
-        match dir {
-            Direction::North => Vec2D(0, 1),
-            Direction::East => Vec2D(1, 0),
-            Direction::South => Vec2D(0, -1),
-            Direction::West => Vec2D(-1, 0),
+    /// Converts to the N-dimensional rover that actually implements movement and turning.
+    fn to_rover_n(self) -> RoverN<2> {
+        RoverN::at(self.position, DirectionN::from_direction(self.direction))
+    }
+
+    fn from_rover_n(rover: RoverN<2>) -> Self {
+        Self {
+            position: rover.position(),
+            direction: rover.direction().to_direction(),
         }
     }
 
     /// Moves the rover one step forward towards the current direction from the current position
     pub fn forward(self, obstacles: &HashSet<Vec2D>) -> Result<Self,Self> {
-        self.try_move(obstacles,self.position.add(Self::dir_to_vec(self.direction)))
+        self.to_rover_n().forward(obstacles).map(Self::from_rover_n).map_err(Self::from_rover_n)
     }
 
     /// Moves the rover one step backward from the current direction from the current position
     pub fn backward(self, obstacles: &HashSet<Vec2D>) -> Result<Self,Self> {
-        self.try_move(obstacles, self.position.sub(Self::dir_to_vec(self.direction)))
-    }
-
-    fn try_move(self, obstacles: &HashSet<Vec2D>, new_pos: Vec2D) -> Result<Self,Self> {
-        if obstacles.contains(&new_pos) {
-            Err(self)
-        } else {
-            Ok(Self {
-                position: new_pos,
-                ..self
-            })
-        }
+        self.to_rover_n().backward(obstacles).map(Self::from_rover_n).map_err(Self::from_rover_n)
     }
 
     /// Turns the rover 90° to the right from the current direction
     pub fn turn_right(self) -> Self {
-        Self {
-            direction: self.direction.turn_right(),
-            ..self
-        }
+        Self::from_rover_n(self.to_rover_n().turn_right(0, 1))
     }
 
     /// Turns the rover 90° to the left from the current direction
     pub fn turn_left(self) -> Self {
-        Self {
-            direction: self.direction.turn_left(),
-            ..self
-        }
+        Self::from_rover_n(self.to_rover_n().turn_left(0, 1))
     }
 
     pub fn get_directions(&self, target: Vec2D, obstacles: &HashSet<Vec2D>) -> String {
@@ -99,6 +95,164 @@ impl Rover {
         String::new()
     }
 
+    /// Like [`Self::get_directions`], but each `F`/`B` costs `move_cost` and each `L`/`R` costs `turn_cost`.
+    pub fn get_directions_weighted(&self, target: Vec2D, obstacles: &HashSet<Vec2D>, move_cost: u32, turn_cost: u32) -> String {
+        struct HeapEntry {
+            priority: u32,
+            cost: u32,
+            rover: Rover,
+            path: ConsList<char>,
+        }
+
+        impl PartialEq for HeapEntry {
+            fn eq(&self, other: &Self) -> bool {
+                self.priority == other.priority
+            }
+        }
+        impl Eq for HeapEntry {}
+        impl PartialOrd for HeapEntry {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for HeapEntry {
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Reversed so `BinaryHeap` (a max-heap) pops the lowest priority first.
+                other.priority.cmp(&self.priority)
+            }
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        fn relax(
+            best_cost: &mut HashMap<Rover, u32>,
+            heap: &mut BinaryHeap<HeapEntry>,
+            path: &ConsList<char>,
+            current_cost: u32,
+            next: Rover,
+            added: u32,
+            symbol: char,
+            target: Vec2D,
+            move_cost: u32,
+        ) {
+            let next_cost = current_cost + added;
+            if next_cost < *best_cost.get(&next).unwrap_or(&u32::MAX) {
+                best_cost.insert(next, next_cost);
+                let heuristic = next.position.manhattan_from(target) as u32 * move_cost;
+                heap.push(HeapEntry {
+                    priority: next_cost + heuristic,
+                    cost: next_cost,
+                    rover: next,
+                    path: path.append(symbol),
+                });
+            }
+        }
+
+        let mut heap = BinaryHeap::new();
+        let mut best_cost: HashMap<Rover, u32> = HashMap::new();
+
+        best_cost.insert(*self, 0);
+        heap.push(HeapEntry {
+            priority: self.position.manhattan_from(target) as u32 * move_cost,
+            cost: 0,
+            rover: *self,
+            path: ConsList::new(),
+        });
+
+        while let Some(HeapEntry { cost, rover, path, .. }) = heap.pop() {
+            if rover.position == target {
+                let mut v: Vec<char> = path.into_iter().copied().collect();
+                v.reverse();
+                return v.into_iter().collect();
+            }
+            if cost > *best_cost.get(&rover).unwrap_or(&u32::MAX) {
+                continue;
+            }
+            relax(&mut best_cost, &mut heap, &path, cost, rover.turn_left(), turn_cost, 'L', target, move_cost);
+            relax(&mut best_cost, &mut heap, &path, cost, rover.turn_right(), turn_cost, 'R', target, move_cost);
+            if let Ok(n) = rover.forward(obstacles) {
+                relax(&mut best_cost, &mut heap, &path, cost, n, move_cost, 'F', target, move_cost);
+            }
+            if let Ok(n) = rover.backward(obstacles) {
+                relax(&mut best_cost, &mut heap, &path, cost, n, move_cost, 'B', target, move_cost);
+            }
+        }
+        String::new()
+    }
+
+    /// Factors `commands` into a main routine over `{A,B,C}` (at most `max_main_len` entries) plus up to
+    /// three subroutine bodies (at most `max_fn_len` chars each), or `None` if no such factoring exists.
+    pub fn compress_path(commands: &str, max_fn_len: usize, max_main_len: usize) -> Option<(String, [String; 3])> {
+        fn solve(
+            remaining: &str,
+            subs: &mut [Option<String>; 3],
+            main: &mut Vec<char>,
+            max_fn_len: usize,
+            max_main_len: usize,
+        ) -> bool {
+            if remaining.is_empty() {
+                return true;
+            }
+            if main.len() >= max_main_len {
+                return false;
+            }
+            for slot in 0..subs.len() {
+                match subs[slot].clone() {
+                    Some(body) => {
+                        if let Some(rest) = remaining.strip_prefix(body.as_str()) {
+                            main.push((b'A' + slot as u8) as char);
+                            if solve(rest, subs, main, max_fn_len, max_main_len) {
+                                return true;
+                            }
+                            main.pop();
+                        }
+                    }
+                    None => {
+                        let max_len = max_fn_len.min(remaining.len());
+                        for len in 1..=max_len {
+                            subs[slot] = Some(remaining[..len].to_string());
+                            main.push((b'A' + slot as u8) as char);
+                            if solve(&remaining[len..], subs, main, max_fn_len, max_main_len) {
+                                return true;
+                            }
+                            main.pop();
+                            subs[slot] = None;
+                        }
+                        return false;
+                    }
+                }
+            }
+            false
+        }
+
+        let mut subs: [Option<String>; 3] = [None, None, None];
+        let mut main = Vec::new();
+        if solve(commands, &mut subs, &mut main, max_fn_len, max_main_len) {
+            let main_routine = main.iter().map(char::to_string).collect::<Vec<_>>().join(",");
+            Some((
+                main_routine,
+                [
+                    subs[0].clone().unwrap_or_default(),
+                    subs[1].clone().unwrap_or_default(),
+                    subs[2].clone().unwrap_or_default(),
+                ],
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Applies the given command `count` times to `self`, stopping early (and
+    /// returning the rover's state at that point) on the first obstacle hit.
+    fn repeat_command(self, obstacles: &HashSet<Vec2D>, cmd: char, count: u32) -> Result<Self, Self> {
+        (0..count).try_fold(self, |r, _| match cmd {
+            'F' => r.forward(obstacles),
+            'B' => r.backward(obstacles),
+            'R' => Ok(r.turn_right()),
+            'L' => Ok(r.turn_left()),
+            _ => unreachable!("repeat_command called with unsupported command {}", cmd),
+        })
+    }
+
     /// Moves the rover according to the given command sequence and returns
     /// the modified rover. If an invalid command is supplied, the unmodified rover is returned.
     ///
@@ -107,14 +261,14 @@ impl Rover {
     /// - B: Move backward in the current direction
     /// - L: Turn left from the current direction
     /// - R: Turn right from the current direction
+    ///
+    /// Each command may carry an optional repeat count as a prefix or suffix (e.g. `"F10"`, `"2L"`).
     pub fn process_sequence<T: AsRef<str>>(self, sequence: T, obstacles: &HashSet<Vec2D>) -> Result<Self, Self> {
-        let res = sequence.as_ref().chars().try_fold(self, |r, c| match c {
-            'F' => r.forward(obstacles),
-            'B' => r.backward(obstacles),
-            'R' => Ok(r.turn_right()),
-            'L' => Ok(r.turn_left()),
-            _ => Err(self),
-        });
+        let res = match tokenize(sequence.as_ref(), |c| matches!(c, 'F' | 'B' | 'R' | 'L')) {
+            Some(tokens) => tokens.into_iter().try_fold(self, |r, (cmd, count)| r.repeat_command(obstacles, cmd, count)),
+            None => Err(self),
+        };
+
         match res {
             Ok(r) => println!("Moved to {}", r),
             Err(r) => println!("Stopped at {}", r),
@@ -122,6 +276,57 @@ impl Rover {
         res
     }
 
+    /// Like [`Self::repeat_command`], but additionally records every cell
+    /// entered (including intermediate cells of a multi-step move) into
+    /// `visited`, tracking the first cell that is entered more than once in
+    /// `first_intersection` via `seen`.
+    fn repeat_command_tracing(
+        self,
+        obstacles: &HashSet<Vec2D>,
+        cmd: char,
+        count: u32,
+        visited: &mut Vec<Vec2D>,
+        seen: &mut HashSet<Vec2D>,
+        first_intersection: &mut Option<Vec2D>,
+    ) -> Result<Self, Self> {
+        (0..count).try_fold(self, |r, _| {
+            let next = match cmd {
+                'F' => r.forward(obstacles),
+                'B' => r.backward(obstacles),
+                'R' => Ok(r.turn_right()),
+                'L' => Ok(r.turn_left()),
+                _ => unreachable!("repeat_command_tracing called with unsupported command {}", cmd),
+            }?;
+            if next.position != r.position {
+                if !seen.insert(next.position) && first_intersection.is_none() {
+                    *first_intersection = Some(next.position);
+                }
+                visited.push(next.position);
+            }
+            Ok(next)
+        })
+    }
+
+    /// Like [`Self::process_sequence`], but also returns the ordered list of visited cells and the first cell entered more than once, if any.
+    pub fn trace_sequence<T: AsRef<str>>(
+        self,
+        sequence: T,
+        obstacles: &HashSet<Vec2D>,
+    ) -> (Result<Self, Self>, Vec<Vec2D>, Option<Vec2D>) {
+        let mut visited = vec![self.position];
+        let mut seen: HashSet<Vec2D> = [self.position].into_iter().collect();
+        let mut first_intersection = None;
+
+        let res = match tokenize(sequence.as_ref(), |c| matches!(c, 'F' | 'B' | 'R' | 'L')) {
+            Some(tokens) => tokens.into_iter().try_fold(self, |r, (cmd, count)| {
+                r.repeat_command_tracing(obstacles, cmd, count, &mut visited, &mut seen, &mut first_intersection)
+            }),
+            None => Err(self),
+        };
+
+        (res, visited, first_intersection)
+    }
+
 
     pub fn move_all(rovers: &[Rover], commands: &[char], obstacles: &HashSet<Vec2D>) -> Vec<Rover> {
         let mut with_rover = obstacles.clone();
@@ -171,10 +376,10 @@ mod tests {
     fn test_north() {
         let obstacles = HashSet::new();
         let start = Rover::new(0,0, Direction::North);
-        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: Vec2D(0,1), direction: Direction::North }));
-        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: Vec2D(0,-1), direction: Direction::North }));
-        assert!(matches!(start.turn_left(), Rover { position: Vec2D(0,0), direction: Direction::West }));
-        assert!(matches!(start.turn_right(), Rover { position: Vec2D(0,0), direction: Direction::East }));
+        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: VecN([0, 1]), direction: Direction::North }));
+        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: VecN([0, -1]), direction: Direction::North }));
+        assert!(matches!(start.turn_left(), Rover { position: VecN([0, 0]), direction: Direction::West }));
+        assert!(matches!(start.turn_right(), Rover { position: VecN([0, 0]), direction: Direction::East }));
 
     }
 
@@ -182,51 +387,51 @@ mod tests {
     fn test_south() {
         let obstacles = HashSet::new();
         let start = Rover::new(0,0, Direction::South);
-        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: Vec2D(0,-1), direction: Direction::South }));
-        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: Vec2D(0,1), direction: Direction::South }));
-        assert!(matches!(start.turn_left(), Rover { position: Vec2D(0,0), direction: Direction::East }));
-        assert!(matches!(start.turn_right(), Rover { position: Vec2D(0,0), direction: Direction::West }));
+        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: VecN([0, -1]), direction: Direction::South }));
+        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: VecN([0, 1]), direction: Direction::South }));
+        assert!(matches!(start.turn_left(), Rover { position: VecN([0, 0]), direction: Direction::East }));
+        assert!(matches!(start.turn_right(), Rover { position: VecN([0, 0]), direction: Direction::West }));
     }
 
     #[test]
     fn test_east() {
         let obstacles = HashSet::new();
         let start = Rover::new(0,0, Direction::East);
-        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: Vec2D(1,0), direction: Direction::East }));
-        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: Vec2D(-1,0), direction: Direction::East }));
-        assert!(matches!(start.turn_left(), Rover { position: Vec2D(0,0), direction: Direction::North }));
-        assert!(matches!(start.turn_right(), Rover { position: Vec2D(0,0), direction: Direction::South }));
+        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: VecN([1, 0]), direction: Direction::East }));
+        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: VecN([-1, 0]), direction: Direction::East }));
+        assert!(matches!(start.turn_left(), Rover { position: VecN([0, 0]), direction: Direction::North }));
+        assert!(matches!(start.turn_right(), Rover { position: VecN([0, 0]), direction: Direction::South }));
     }
 
     #[test]
     fn test_west() {let obstacles = HashSet::new();
         let start = Rover::new(0,0, Direction::West);
-        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: Vec2D(-1,0), direction: Direction::West }));
-        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: Vec2D(1,0), direction: Direction::West }));
-        assert!(matches!(start.turn_left(), Rover { position: Vec2D(0,0), direction: Direction::South }));
-        assert!(matches!(start.turn_right(), Rover { position: Vec2D(0,0), direction: Direction::North }));
+        assert!(matches!(start.forward(&obstacles).unwrap(), Rover { position: VecN([-1, 0]), direction: Direction::West }));
+        assert!(matches!(start.backward(&obstacles).unwrap(), Rover { position: VecN([1, 0]), direction: Direction::West }));
+        assert!(matches!(start.turn_left(), Rover { position: VecN([0, 0]), direction: Direction::South }));
+        assert!(matches!(start.turn_right(), Rover { position: VecN([0, 0]), direction: Direction::North }));
     }
 
     #[test]
     fn test_obstacle_fw() {
-        let obsts = [Vec2D(0,1)].into_iter().collect();
+        let obsts = [VecN([0, 1])].into_iter().collect();
         let start = Rover::new(0,0, Direction::North);
         assert!(start.forward(&obsts).is_err());
     }
 
     #[test]
     fn test_obstacle_bw() {
-        let obsts = [Vec2D(0,-1)].into_iter().collect();
+        let obsts = [VecN([0, -1])].into_iter().collect();
         let start = Rover::new(0,0, Direction::North);
         assert!(start.backward(&obsts).is_err());
     }
 
     #[test]
     fn test_example_sequence_obst() {
-        let obstacles = [Vec2D(0,3)].into_iter().collect();
+        let obstacles = [VecN([0, 3])].into_iter().collect();
         let start = Rover::new(0, 0, Direction::North);
         let moved = start.clone().process_sequence("FFFLFFRBRF",&obstacles);
-        assert!(matches!(moved, Err(Rover { position: Vec2D(0,2), direction: Direction::North })));
+        assert!(matches!(moved, Err(Rover { position: VecN([0, 2]), direction: Direction::North })));
     }
 
     #[test]
@@ -237,6 +442,80 @@ mod tests {
         assert_eq!(moved, Rover::new(-1, 2, Direction::East));
     }
 
+    #[test]
+    fn test_sequence_with_repeat_counts_suffix() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let moved = start.process_sequence("F10R2F1", &obstacles).unwrap();
+        assert_eq!(moved, Rover::new(0, 9, Direction::South));
+    }
+
+    #[test]
+    fn test_sequence_with_repeat_counts_prefix() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let moved = start.process_sequence("10F", &obstacles).unwrap();
+        assert_eq!(moved, Rover::new(0, 10, Direction::North));
+    }
+
+    #[test]
+    fn test_sequence_with_repeat_count_stops_at_obstacle() {
+        let obstacles = [VecN([0, 5])].into_iter().collect();
+        let start = Rover::new(0, 0, Direction::North);
+        let moved = start.process_sequence("F10", &obstacles);
+        assert!(matches!(moved, Err(Rover { position: VecN([0, 4]), direction: Direction::North })));
+    }
+
+    #[test]
+    fn test_sequence_with_repeat_count_overflow_is_rejected() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        assert!(matches!(
+            start.process_sequence("99999999999999F", &obstacles),
+            Err(Rover {
+                position: VecN([0, 0]),
+                direction: Direction::North
+            })
+        ));
+    }
+
+    #[test]
+    fn test_trace_sequence_records_trail_and_distance() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let (res, visited, crossing) = start.trace_sequence("F3R2F3", &obstacles);
+        let moved = res.unwrap();
+        assert_eq!(visited, vec![VecN([0, 0]), VecN([0, 1]), VecN([0, 2]), VecN([0, 3]), VecN([0, 2]), VecN([0, 1]), VecN([0, 0])]);
+        assert_eq!(crossing, Some(VecN([0, 2])));
+        assert_eq!(moved.position.manhattan_from(start.position), 0);
+    }
+
+    #[test]
+    fn test_trace_sequence_no_self_intersection() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let (res, visited, crossing) = start.trace_sequence("F3", &obstacles);
+        assert!(res.is_ok());
+        assert_eq!(visited, vec![VecN([0, 0]), VecN([0, 1]), VecN([0, 2]), VecN([0, 3])]);
+        assert_eq!(crossing, None);
+    }
+
+    #[test]
+    fn test_trace_sequence_repeat_count_overflow_is_rejected() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let (res, visited, crossing) = start.trace_sequence("99999999999999F", &obstacles);
+        assert!(matches!(
+            res,
+            Err(Rover {
+                position: VecN([0, 0]),
+                direction: Direction::North
+            })
+        ));
+        assert_eq!(visited, vec![VecN([0, 0])]);
+        assert_eq!(crossing, None);
+    }
+
     #[test]
     fn test_empty_sequence() {
         let obstacles = HashSet::new();
@@ -244,7 +523,7 @@ mod tests {
         assert!(matches!(
             start.process_sequence("",&obstacles),
             Ok(Rover {
-                position: Vec2D(0, 0),
+                position: VecN([0, 0]),
                 direction: Direction::North
             })
         ));
@@ -257,7 +536,7 @@ mod tests {
         assert!(matches!(
             start.process_sequence("FFX", &obstacles),
             Err(Rover {
-                position: Vec2D(0, 0),
+                position: VecN([0, 0]),
                 direction: Direction::North
             })
         ))
@@ -267,17 +546,60 @@ mod tests {
     fn test_path_no_obstacles() {
         let obstacles = HashSet::new();
         let start = Rover::new(0, 0, Direction::North);
-        let r = start.get_directions(Vec2D(3,0), &obstacles );
+        let r = start.get_directions(VecN([3, 0]), &obstacles );
         assert_eq!(r,"LBBB")
     }
 
     #[test]
     fn test_path_with_obstacles() {
-        let obstacles = [Vec2D(2,0)].into_iter().collect();
+        let obstacles = [VecN([2, 0])].into_iter().collect();
         let start = Rover::new(0, 0, Direction::North);
-        let r = start.get_directions(Vec2D(3,0), &obstacles );
+        let r = start.get_directions(VecN([3, 0]), &obstacles );
         assert_eq!(r,"FLBBBLF");
-        assert_eq!(Vec2D(3,0), start.process_sequence(r,&obstacles).unwrap().position);
+        assert_eq!(VecN([3, 0]), start.process_sequence(r,&obstacles).unwrap().position);
+    }
+
+    #[test]
+    fn test_weighted_path_no_obstacles_matches_uniform_cost() {
+        let obstacles = HashSet::new();
+        let start = Rover::new(0, 0, Direction::North);
+        let r = start.get_directions_weighted(VecN([3, 0]), &obstacles, 1, 1);
+        assert_eq!(r, "LBBB")
+    }
+
+    #[test]
+    fn test_weighted_path_with_obstacles() {
+        let obstacles = [VecN([2, 0])].into_iter().collect();
+        let start = Rover::new(0, 0, Direction::North);
+        let r = start.get_directions_weighted(VecN([3, 0]), &obstacles, 1, 1);
+        assert_eq!(VecN([3, 0]), start.process_sequence(r, &obstacles).unwrap().position);
+    }
+
+    #[test]
+    fn test_weighted_path_prefers_fewer_turns_when_turning_is_expensive() {
+        let target = VecN([5, -1]);
+        let obstacles: HashSet<Vec2D> = [VecN([2, -1]), VecN([4, 0])].into_iter().collect();
+        let start = Rover::new(0, 0, Direction::North);
+
+        let cheap_turns = start.get_directions_weighted(target, &obstacles, 1, 1);
+        assert_eq!(cheap_turns, "LBBBRBRFF");
+        assert_eq!(VecN([5, -1]), start.process_sequence(cheap_turns, &obstacles).unwrap().position);
+
+        let expensive_turns = start.get_directions_weighted(target, &obstacles, 1, 20);
+        assert_eq!(expensive_turns, "BBLBBBBBRF");
+        assert_eq!(VecN([5, -1]), start.process_sequence(expensive_turns, &obstacles).unwrap().position);
+    }
+
+    #[test]
+    fn test_compress_path_finds_repeated_subroutine() {
+        let (main, subs) = Rover::compress_path("FLFLFLFL", 2, 4).unwrap();
+        assert_eq!(main, "A,A,A,A");
+        assert_eq!(subs, ["FL".to_string(), "".to_string(), "".to_string()]);
+    }
+
+    #[test]
+    fn test_compress_path_no_factoring_exists() {
+        assert_eq!(Rover::compress_path("FBLR", 1, 1), None);
     }
 
     #[test]
@@ -310,7 +632,7 @@ mod tests {
 
     #[test]
     fn test_move_all_collision_with_obstacles() {
-        let obstacles = [Vec2D(1,0)].into_iter().collect();
+        let obstacles = [VecN([1, 0])].into_iter().collect();
         let rovers = [
             Rover::new(0,0, Direction::East),
             Rover::new( 2, 0, Direction::West)
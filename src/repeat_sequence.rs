@@ -0,0 +1,95 @@
+/// Tokenizes a command sequence into `(command, repeat count)` pairs, where a
+/// bare letter counts as 1 and an unsigned repeat count may be given as a
+/// prefix or a suffix (e.g. `"F3"`, `"3F"`). Shared by
+/// [`crate::Rover::process_sequence`], [`crate::Rover::trace_sequence`] and
+/// [`crate::WaypointRover::process_sequence`], which differ only in which
+/// letters `is_valid` accepts and how each token is applied.
+///
+/// Returns `None` if the sequence contains a character rejected by
+/// `is_valid`, ends in trailing digits with no command, or carries a repeat
+/// count that overflows `u32`.
+pub(crate) fn tokenize(sequence: &str, is_valid: impl Fn(char) -> bool) -> Option<Vec<(char, u32)>> {
+    #[derive(Clone, Copy)]
+    enum Pending {
+        None,
+        Count(u32),
+        Cmd(char, Option<u32>),
+    }
+
+    fn checked_accumulate(n: u32, digit: u32) -> Option<u32> {
+        n.checked_mul(10)?.checked_add(digit)
+    }
+
+    let mut tokens = Vec::new();
+    let flush = |tokens: &mut Vec<(char, u32)>, pending: Pending| -> Option<()> {
+        match pending {
+            Pending::None => Some(()),
+            Pending::Count(_) => None,
+            Pending::Cmd(cmd, count) => {
+                tokens.push((cmd, count.unwrap_or(1)));
+                Some(())
+            }
+        }
+    };
+
+    let last = sequence.chars().try_fold(Pending::None, |pending, c| {
+        if let Some(digit) = c.to_digit(10) {
+            match pending {
+                Pending::None => Some(Pending::Count(digit)),
+                Pending::Count(n) => checked_accumulate(n, digit).map(Pending::Count),
+                Pending::Cmd(cmd, None) => Some(Pending::Cmd(cmd, Some(digit))),
+                Pending::Cmd(cmd, Some(n)) => checked_accumulate(n, digit).map(|n| Pending::Cmd(cmd, Some(n))),
+            }
+        } else if is_valid(c) {
+            match pending {
+                Pending::None => Some(Pending::Cmd(c, None)),
+                Pending::Count(n) => Some(Pending::Cmd(c, Some(n))),
+                Pending::Cmd(_, _) => {
+                    flush(&mut tokens, pending)?;
+                    Some(Pending::Cmd(c, None))
+                }
+            }
+        } else {
+            None
+        }
+    })?;
+
+    flush(&mut tokens, last)?;
+    Some(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_frbl(c: char) -> bool {
+        matches!(c, 'F' | 'B' | 'R' | 'L')
+    }
+
+    #[test]
+    fn test_tokenize_bare_letters() {
+        assert_eq!(tokenize("FBRL", is_frbl), Some(vec![('F', 1), ('B', 1), ('R', 1), ('L', 1)]));
+    }
+
+    #[test]
+    fn test_tokenize_prefix_and_suffix_counts() {
+        assert_eq!(tokenize("10FR2", is_frbl), Some(vec![('F', 10), ('R', 2)]));
+    }
+
+    #[test]
+    fn test_tokenize_rejects_invalid_char() {
+        assert_eq!(tokenize("FX", is_frbl), None);
+    }
+
+    #[test]
+    fn test_tokenize_rejects_trailing_count_without_command() {
+        assert_eq!(tokenize("F10", is_frbl), Some(vec![('F', 10)]));
+        assert_eq!(tokenize("F1", is_frbl), Some(vec![('F', 1)]));
+        assert_eq!(tokenize("10", is_frbl), None);
+    }
+
+    #[test]
+    fn test_tokenize_rejects_count_overflow() {
+        assert_eq!(tokenize("99999999999999F", is_frbl), None);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::direction_n::DirectionN;
+use crate::vecn::VecN;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+
+/// The N-dimensional generalization of [`crate::Rover`]: a rover described by
+/// a position in a `D`-dimensional integer grid and an axis-aligned facing
+/// direction. This opens the 2D simulator up to volumetric grids and
+/// face/voxel navigation scenarios.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct RoverN<const D: usize> {
+    position: VecN<D>,
+    direction: DirectionN<D>,
+}
+
+impl<const D: usize> RoverN<D> {
+    /// Creates a new rover at the given position facing the given direction, without the
+    /// "Landed at" side effect of [`Self::new`]; used internally to move between states.
+    pub(crate) fn at(position: VecN<D>, direction: DirectionN<D>) -> Self {
+        Self { position, direction }
+    }
+
+    pub(crate) fn position(self) -> VecN<D> {
+        self.position
+    }
+
+    pub(crate) fn direction(self) -> DirectionN<D> {
+        self.direction
+    }
+
+    /// Creates a new rover at the given position facing the given direction.
+    pub fn new(position: VecN<D>, direction: DirectionN<D>) -> Self {
+        let res = Self::at(position, direction);
+        println!("Landed at {}", res);
+        res
+    }
+
+    /// Moves the rover one step forward towards the current direction from the current position
+    pub fn forward(self, obstacles: &HashSet<VecN<D>>) -> Result<Self, Self> {
+        self.try_move(obstacles, self.position.add(self.direction.to_vec()))
+    }
+
+    /// Moves the rover one step backward from the current direction from the current position
+    pub fn backward(self, obstacles: &HashSet<VecN<D>>) -> Result<Self, Self> {
+        self.try_move(obstacles, self.position.sub(self.direction.to_vec()))
+    }
+
+    fn try_move(self, obstacles: &HashSet<VecN<D>>, new_pos: VecN<D>) -> Result<Self, Self> {
+        if obstacles.contains(&new_pos) {
+            Err(self)
+        } else {
+            Ok(Self {
+                position: new_pos,
+                ..self
+            })
+        }
+    }
+
+    /// Turns the rover 90° to the right within the plane spanned by `axis_a`
+    /// and `axis_b`; see [`DirectionN::rotate_right`].
+    pub fn turn_right(self, axis_a: usize, axis_b: usize) -> Self {
+        Self {
+            direction: self.direction.rotate_right(axis_a, axis_b),
+            ..self
+        }
+    }
+
+    /// Turns the rover 90° to the left within the plane spanned by `axis_a`
+    /// and `axis_b`; see [`DirectionN::rotate_left`].
+    pub fn turn_left(self, axis_a: usize, axis_b: usize) -> Self {
+        Self {
+            direction: self.direction.rotate_left(axis_a, axis_b),
+            ..self
+        }
+    }
+}
+
+impl<const D: usize> Display for RoverN<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:?}", self.position, self.direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_3d_forward_and_backward() {
+        let obstacles = HashSet::new();
+        let start = RoverN::new(VecN([0, 0, 0]), DirectionN::positive(2));
+        assert_eq!(start.forward(&obstacles).unwrap().position, VecN([0, 0, 1]));
+        assert_eq!(start.backward(&obstacles).unwrap().position, VecN([0, 0, -1]));
+    }
+
+    #[test]
+    fn test_3d_forward_obstacle() {
+        let obstacles = [VecN([0, 0, 1])].into_iter().collect();
+        let start = RoverN::new(VecN([0, 0, 0]), DirectionN::positive(2));
+        assert!(start.forward(&obstacles).is_err());
+    }
+
+    #[test]
+    fn test_3d_turn_stays_in_plane() {
+        let start = RoverN::new(VecN([0, 0, 0]), DirectionN::positive(0));
+        let turned = start.turn_right(0, 1);
+        assert_eq!(turned.direction, DirectionN::negative(1));
+
+        let unaffected = start.turn_right(1, 2);
+        assert_eq!(unaffected.direction, start.direction);
+    }
+}
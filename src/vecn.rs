@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Sub};
+
+/// A `D`-dimensional integer vector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct VecN<const D: usize>(pub [i32; D]);
+
+impl<const D: usize> Add<Self> for VecN<D> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut res = [0; D];
+        for (r, (a, b)) in res.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            *r = a + b;
+        }
+        VecN(res)
+    }
+}
+
+impl<const D: usize> Sub<Self> for VecN<D> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut res = [0; D];
+        for (r, (a, b)) in res.iter_mut().zip(self.0.iter().zip(rhs.0.iter())) {
+            *r = a - b;
+        }
+        VecN(res)
+    }
+}
+
+impl<const D: usize> Display for VecN<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, v) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", v)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<const D: usize> VecN<D> {
+    /// Computes the Manhattan (taxicab) distance between `self` and `origin`,
+    /// i.e. the sum of the absolute per-axis differences.
+    pub fn manhattan_from(self, origin: Self) -> i32 {
+        (0..D).map(|i| (self.0[i] - origin.0[i]).abs()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        let v1 = VecN([10, 10, 10]);
+        let v2 = VecN([1, 2, 3]);
+
+        assert_eq!(v1.add(v2), VecN([11, 12, 13]))
+    }
+
+    #[test]
+    fn test_sub() {
+        let v1 = VecN([10, 10, 10]);
+        let v2 = VecN([1, 2, 3]);
+
+        assert_eq!(v1.sub(v2), VecN([9, 8, 7]))
+    }
+
+    #[test]
+    fn test_manhattan_from() {
+        let v1 = VecN([3, -4, 1]);
+        let origin = VecN([0, 0, 0]);
+
+        assert_eq!(v1.manhattan_from(origin), 8)
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(VecN([1, -2, 3]).to_string(), "(1, -2, 3)")
+    }
+}
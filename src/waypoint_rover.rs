@@ -0,0 +1,170 @@
+use crate::repeat_sequence::tokenize;
+use crate::vec2d::Vec2D;
+use crate::vecn::VecN;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+
+/// A mars rover steered by aiming a waypoint: instead of turning itself and
+/// moving one cell at a time (see [`crate::Rover`]), it carries a waypoint
+/// offset relative to its own position and moves towards it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct WaypointRover {
+    position: Vec2D,
+    waypoint: Vec2D,
+}
+
+impl WaypointRover {
+    /// Creates a new rover at the given x and y coordinates with the given
+    /// waypoint offset relative to its position.
+    pub fn new(x: i32, y: i32, waypoint: Vec2D) -> Self {
+        let res = Self {
+            position: VecN([x, y]),
+            waypoint,
+        };
+        println!("Landed at {}", res);
+        res
+    }
+
+    /// Rotates the waypoint 90° to the right around the rover.
+    pub fn turn_right(self) -> Self {
+        let VecN([x, y]) = self.waypoint;
+        Self {
+            waypoint: VecN([y, -x]),
+            ..self
+        }
+    }
+
+    /// Rotates the waypoint 90° to the left around the rover.
+    pub fn turn_left(self) -> Self {
+        let VecN([x, y]) = self.waypoint;
+        Self {
+            waypoint: VecN([-y, x]),
+            ..self
+        }
+    }
+
+    fn try_move(self, obstacles: &HashSet<Vec2D>, new_pos: Vec2D) -> Result<Self, Self> {
+        if obstacles.contains(&new_pos) {
+            Err(self)
+        } else {
+            Ok(Self {
+                position: new_pos,
+                ..self
+            })
+        }
+    }
+
+    /// Moves the rover one step towards its waypoint, i.e. translates its
+    /// position by the waypoint offset.
+    pub fn forward(self, obstacles: &HashSet<Vec2D>) -> Result<Self, Self> {
+        self.try_move(obstacles, self.position.add(self.waypoint))
+    }
+
+    /// Applies the given command `count` times to `self`, stopping early (and
+    /// returning the rover's state at that point) on the first obstacle hit.
+    fn repeat_command(self, obstacles: &HashSet<Vec2D>, cmd: char, count: u32) -> Result<Self, Self> {
+        match cmd {
+            'N' | 'S' | 'E' | 'W' => {
+                let shift = i32::try_from(count).map_err(|_| self)?;
+                let shift = if matches!(cmd, 'S' | 'W') { -shift } else { shift };
+                let offset = if matches!(cmd, 'N' | 'S') { VecN([0, shift]) } else { VecN([shift, 0]) };
+                Ok(Self { waypoint: self.waypoint.add(offset), ..self })
+            }
+            'L' => Ok((0..count).fold(self, |r, _| r.turn_left())),
+            'R' => Ok((0..count).fold(self, |r, _| r.turn_right())),
+            'F' => (0..count).try_fold(self, |r, _| r.forward(obstacles)),
+            _ => unreachable!("repeat_command called with unsupported command {}", cmd),
+        }
+    }
+
+    /// Moves the rover according to the given command sequence and returns
+    /// the modified rover. If an invalid command is supplied, the unmodified
+    /// rover is returned.
+    ///
+    /// Valid commands are:
+    /// - N/E/S/W: Shift the waypoint north/east/south/west
+    /// - F: Move the rover towards its waypoint
+    /// - L/R: Rotate the waypoint 90° left/right around the rover
+    ///
+    /// As with [`crate::Rover::process_sequence`], each command may carry an
+    /// unsigned repeat count as a prefix or a suffix (e.g. `"F3"`, `"N10"`,
+    /// `"2R"`); a bare letter means a count of 1. Parsing stops and
+    /// `Err(self)` (with progress made so far) is returned on the first
+    /// obstacle hit while moving forward.
+    pub fn process_sequence<T: AsRef<str>>(self, sequence: T, obstacles: &HashSet<Vec2D>) -> Result<Self, Self> {
+        let res = match tokenize(sequence.as_ref(), |c| matches!(c, 'N' | 'E' | 'S' | 'W' | 'F' | 'L' | 'R')) {
+            Some(tokens) => tokens.into_iter().try_fold(self, |r, (cmd, count)| r.repeat_command(obstacles, cmd, count)),
+            None => Err(self),
+        };
+
+        match res {
+            Ok(r) => println!("Moved to {}", r),
+            Err(r) => println!("Stopped at {}", r),
+        }
+        res
+    }
+}
+
+impl Display for WaypointRover {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (waypoint {})", self.position, self.waypoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_moves_towards_waypoint() {
+        let obstacles = HashSet::new();
+        let start = WaypointRover::new(0, 0, VecN([10, 4]));
+        let moved = start.forward(&obstacles).unwrap();
+        assert_eq!(moved, WaypointRover::new(10, 4, VecN([10, 4])));
+    }
+
+    #[test]
+    fn test_turn_right_rotates_waypoint_around_rover() {
+        let start = WaypointRover::new(0, 0, VecN([10, 4]));
+        assert_eq!(start.turn_right(), WaypointRover::new(0, 0, VecN([4, -10])));
+    }
+
+    #[test]
+    fn test_turn_left_rotates_waypoint_around_rover() {
+        let start = WaypointRover::new(0, 0, VecN([10, 4]));
+        assert_eq!(start.turn_left(), WaypointRover::new(0, 0, VecN([-4, 10])));
+    }
+
+    #[test]
+    fn test_process_sequence_example() {
+        let obstacles = HashSet::new();
+        let start = WaypointRover::new(0, 0, VecN([10, 1]));
+        let moved = start.process_sequence("F10N3F7R1F11", &obstacles).unwrap();
+        assert_eq!(moved, WaypointRover::new(214, -72, VecN([4, -10])));
+    }
+
+    #[test]
+    fn test_process_sequence_stops_at_obstacle() {
+        let obstacles = [VecN([20, 2])].into_iter().collect();
+        let start = WaypointRover::new(0, 0, VecN([10, 1]));
+        let moved = start.process_sequence("F3", &obstacles);
+        assert!(matches!(moved, Err(WaypointRover { position: VecN([10, 1]), waypoint: VecN([10, 1]) })));
+    }
+
+    #[test]
+    fn test_process_sequence_repeat_count_overflow_is_rejected() {
+        let obstacles = HashSet::new();
+        let start = WaypointRover::new(0, 0, VecN([10, 1]));
+        let moved = start.process_sequence("99999999999999F", &obstacles);
+        assert!(matches!(moved, Err(WaypointRover { position: VecN([0, 0]), waypoint: VecN([10, 1]) })));
+    }
+
+    #[test]
+    fn test_process_sequence_rejects_count_not_fitting_i32() {
+        let obstacles = HashSet::new();
+        let start = WaypointRover::new(0, 0, VecN([0, 0]));
+        let moved = start.process_sequence("3000000000N", &obstacles);
+        assert!(matches!(moved, Err(WaypointRover { position: VecN([0, 0]), waypoint: VecN([0, 0]) })));
+    }
+}